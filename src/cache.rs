@@ -0,0 +1,73 @@
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// releases 列表缓存的存活时间：超过这个时长就认为过期，需要重新从 GitHub 拉取
+const RELEASES_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// 本地缓存目录：既保存下载好的压缩包（重装时命中即不再下载压缩包），也保存 releases API 的响应
+///
+/// 命中压缩包缓存的重装仍会联网：releases 缓存过期后要重新拉取 release 列表，
+/// 未使用 `--skip-verify` 时还要下载校验和文件，因此重装不能完全离线进行。
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(base_dir: &Path) -> Result<Self> {
+        let dir = base_dir.join("cache");
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn releases_path(&self) -> PathBuf {
+        self.dir.join("releases.json")
+    }
+
+    /// 读取尚未过期的 releases 缓存；缓存不存在或已过期时返回 `None`
+    pub fn read_releases(&self) -> Option<String> {
+        let path = self.releases_path();
+        let modified = fs::metadata(&path).ok()?.modified().ok()?;
+        if modified.elapsed().ok()? > RELEASES_CACHE_TTL {
+            return None;
+        }
+        fs::read_to_string(&path).ok()
+    }
+
+    pub fn write_releases(&self, body: &str) -> Result<()> {
+        fs::write(self.releases_path(), body)?;
+        Ok(())
+    }
+
+    /// 某个资源在缓存中的路径，按资源名和摘要命名，便于跨安装复用同一份下载
+    pub fn archive_path(&self, asset_name: &str, digest: Option<&str>) -> PathBuf {
+        match digest {
+            Some(d) => self.dir.join(format!("{}-{}", d, asset_name)),
+            None => self.dir.join(asset_name),
+        }
+    }
+
+    pub fn clear(&self) -> Result<()> {
+        if self.dir.exists() {
+            fs::remove_dir_all(&self.dir)?;
+        }
+        fs::create_dir_all(&self.dir)?;
+        Ok(())
+    }
+
+    /// 缓存目录的总大小（字节），用于 `suivm info`
+    pub fn size_bytes(&self) -> Result<u64> {
+        let mut total = 0u64;
+        if self.dir.exists() {
+            for entry in fs::read_dir(&self.dir)? {
+                total += entry?.metadata()?.len();
+            }
+        }
+        Ok(total)
+    }
+}
@@ -1,8 +1,13 @@
+mod cache;
+mod project_pin;
+mod shim;
 mod utils;
 mod version_manager;
+mod version_spec;
 
 use clap::{Parser, Subcommand};
 use version_manager::SuiVersionManager;
+use version_spec::VersionSpec;
 
 #[derive(Parser)]
 #[command(name = "suivm")]
@@ -10,21 +15,34 @@ use version_manager::SuiVersionManager;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Override the resolved version for this invocation, bypassing the project pin and global default
+    #[arg(long = "use", global = true, value_name = "SPEC")]
+    use_version: Option<String>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// List all available Sui versions
-    List,
-    /// Install a specific version
+    List {
+        /// How many pages of releases to fetch (100 per page); ignored with --all
+        #[arg(long)]
+        limit: Option<u32>,
+        /// Fetch the complete release history instead of stopping at a page limit
+        #[arg(long)]
+        all: bool,
+    },
+    /// Install a specific version (accepts `latest`, `stable`, a semver range, or an exact tag)
     Install {
         #[arg(name = "VERSION")]
-        version: String,
+        version: VersionSpec,
+        /// Skip SHA-256 checksum verification of the downloaded archive
+        #[arg(long)]
+        skip_verify: bool,
     },
-    /// Uninstall a specific version
+    /// Uninstall a specific version (accepts `latest`, `stable`, a semver range, or an exact tag)
     Uninstall {
         #[arg(name = "VERSION")]
-        version: String,
+        version: VersionSpec,
     },
     /// Show shell configuration
     Config {
@@ -32,11 +50,36 @@ enum Commands {
         #[arg(help = "Shell type (bash/zsh/fish)")]
         shell: Shell,
     },
-    /// Set default version
+    /// Set default version (accepts `latest`, `stable`, a semver range, or an exact tag)
     Use {
         #[arg(name = "VERSION")]
-        version: String,
+        version: VersionSpec,
+    },
+    /// Pin the current directory to a specific version spec via `.suivm-version`
+    Pin {
+        #[arg(name = "SPEC")]
+        version: VersionSpec,
+    },
+    /// Run a specific installed version's `sui` binary
+    Exec {
+        #[arg(name = "VERSION")]
+        version: VersionSpec,
+        /// Arguments forwarded to `sui`, after `--`
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// Run the active version's `sui` binary (honoring `--use`, the project pin, and the global default)
+    Run {
+        /// Arguments forwarded to `sui`, after `--`
+        #[arg(last = true)]
+        args: Vec<String>,
     },
+    /// Generate PATH shims in `~/.suivm/bin` that dispatch to the active version at call time
+    Remap,
+    /// Remove all cached archives and the releases list cache
+    ClearCache,
+    /// Print environment and installation diagnostics
+    Info,
 }
 
 #[derive(clap::ValueEnum, Clone)]
@@ -50,16 +93,8 @@ enum Shell {
 async fn main() {
     let cli = Cli::parse();
 
-    // 验证操作系统和架构
-    let os_name = utils::get_os_name();
-    if os_name != "macos" {
-        println!("Sorry, this program only supports macOS.");
-        return;
-    }
-    let _cpu_arch = utils::get_cpu_arch();
-
     // 初始化版本管理器
-    let manager = match SuiVersionManager::new() {
+    let manager = match SuiVersionManager::with_override(cli.use_version) {
         Ok(m) => m,
         Err(e) => {
             println!("Failed to initialize version manager: {}", e);
@@ -68,34 +103,43 @@ async fn main() {
     };
 
     match cli.command {
-        Commands::List => match manager.list_remote_versions().await {
-            Ok(versions) => {
-                println!("Available versions:");
-                for (version, is_installed, is_default) in versions {
-                    let install_marker = if is_installed { "[*]" } else { "[ ]" };
-                    let default_marker = if is_default { " (default)" } else { "" };
-                    println!("{} {}{}", install_marker, version, default_marker);
+        Commands::List { limit, all } => {
+            let max_pages = if all {
+                None
+            } else {
+                Some(limit.unwrap_or(version_manager::DEFAULT_PAGE_LIMIT))
+            };
+            match manager.list_remote_versions(max_pages).await {
+                Ok(versions) => {
+                    println!("Available versions:");
+                    for (version, is_installed, is_default) in versions {
+                        let install_marker = if is_installed { "[*]" } else { "[ ]" };
+                        let default_marker = if is_default { " (default)" } else { "" };
+                        println!("{} {}{}", install_marker, version, default_marker);
+                    }
                 }
+                Err(e) => println!("Failed to fetch versions: {}", e),
             }
-            Err(e) => println!("Failed to fetch versions: {}", e),
-        },
-        Commands::Install { version } => {
-            println!("Installing version: {}", version);
-            match manager.download_version(&version).await {
-                Ok(_) => {
-                    println!("Successfully installed version {}", version);
+        }
+        Commands::Install {
+            version,
+            skip_verify,
+        } => {
+            println!("Resolving and installing version: {}", version);
+            match manager.download_version(&version, skip_verify).await {
+                Ok(resolved) => {
+                    println!("Successfully installed version {}", resolved);
                     println!("\nTo configure shell integration, run:");
                     println!("  suivm config bash  # for bash");
                     println!("  suivm config zsh   # for zsh");
                 }
-                Err(e) => println!("Failed to install version {}: {}", version, e),
+                Err(e) => println!("Failed to install version: {}", e),
             }
         }
         Commands::Uninstall { version } => {
-            println!("Uninstalling version: {}", version);
             match manager.uninstall_version(&version) {
-                Ok(_) => println!("Successfully uninstalled version {}", version),
-                Err(e) => println!("Failed to uninstall version {}: {}", version, e),
+                Ok(resolved) => println!("Successfully uninstalled version {}", resolved),
+                Err(e) => println!("Failed to uninstall version: {}", e),
             }
         }
         Commands::Config { shell } => {
@@ -124,11 +168,62 @@ async fn main() {
             }
         }
         Commands::Use { version } => {
-            println!("Setting default version to: {}", version);
             match manager.set_default_version(&version) {
-                Ok(_) => println!("Successfully set default version to {}", version),
+                Ok(resolved) => println!("Successfully set default version to {}", resolved),
                 Err(e) => println!("Failed to set default version: {}", e),
             }
         }
+        Commands::Pin { version } => match manager.pin_version(&version) {
+            Ok(path) => println!("Pinned this directory to {} via {}", version, path.display()),
+            Err(e) => println!("Failed to pin version: {}", e),
+        },
+        Commands::Exec { version, args } => match manager.exec(&version, &args) {
+            Ok(code) => std::process::exit(code),
+            Err(e) => {
+                println!("Failed to exec: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::Run { args } => match manager.run(&args) {
+            Ok(code) => std::process::exit(code),
+            Err(e) => {
+                println!("Failed to run: {}", e);
+                std::process::exit(1);
+            }
+        },
+        Commands::Remap => match manager.remap() {
+            Ok(path) => println!(
+                "Installed shim at {}. Add {} to your PATH.",
+                path.display(),
+                path.parent().map(|p| p.display().to_string()).unwrap_or_default()
+            ),
+            Err(e) => println!("Failed to generate shim: {}", e),
+        },
+        Commands::ClearCache => match manager.clear_cache() {
+            Ok(()) => println!("Cache cleared"),
+            Err(e) => println!("Failed to clear cache: {}", e),
+        },
+        Commands::Info => match manager.info() {
+            Ok(info) => {
+                println!("OS/arch:            {}/{}", info.os_name, info.cpu_arch);
+                println!("Base dir:           {}", info.base_dir.display());
+                println!(
+                    "Current version:    {}",
+                    info.current_version.as_deref().unwrap_or("(none)")
+                );
+                println!(
+                    "Installed versions: {}",
+                    if info.installed_versions.is_empty() {
+                        "(none)".to_string()
+                    } else {
+                        info.installed_versions.join(", ")
+                    }
+                );
+                println!("Cache dir:          {}", info.cache_dir.display());
+                println!("Cache size:         {} bytes", info.cache_size_bytes);
+                println!("sui binary present: {}", info.binary_present);
+            }
+            Err(e) => println!("Failed to collect diagnostics: {}", e),
+        },
     }
 }
@@ -0,0 +1,123 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+const VERSION_FILE: &str = ".suivm-version";
+const TOML_FILE: &str = "suivm.toml";
+
+/// 从当前工作目录开始向上查找项目级别的版本固定（`.suivm-version` 或 `suivm.toml`）
+pub fn find_pinned_spec() -> Option<String> {
+    let cwd = env::current_dir().ok()?;
+    find_pinned_spec_from(&cwd)
+}
+
+fn find_pinned_spec_from(start: &Path) -> Option<String> {
+    let mut dir = Some(start.to_path_buf());
+
+    while let Some(d) = dir {
+        if let Some(spec) = read_version_file(&d.join(VERSION_FILE)) {
+            return Some(spec);
+        }
+        if let Some(spec) = read_toml_file(&d.join(TOML_FILE)) {
+            return Some(spec);
+        }
+        dir = d.parent().map(PathBuf::from);
+    }
+
+    None
+}
+
+fn read_version_file(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let trimmed = content.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+fn read_toml_file(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let value: toml::Value = content.parse().ok()?;
+    value
+        .get("toolchain")?
+        .get("version")?
+        .as_str()
+        .map(|s| s.to_string())
+}
+
+/// 在当前工作目录写入 `.suivm-version`，固定该目录（及其子目录）使用的版本
+pub fn write_pin(spec: &str) -> Result<PathBuf> {
+    let cwd = env::current_dir()?;
+    let path = cwd.join(VERSION_FILE);
+    fs::write(&path, format!("{}\n", spec))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    // 没有引入 tempdir 依赖，用进程 id + 自增计数器在系统临时目录下拼出独立的测试目录
+    fn unique_tmp_dir() -> PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = env::temp_dir().join(format!("suivm_project_pin_test_{}_{}", std::process::id(), n));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn finds_version_file_in_a_parent_directory() {
+        let root = unique_tmp_dir();
+        let child = root.join("a").join("b");
+        fs::create_dir_all(&child).unwrap();
+        fs::write(root.join(VERSION_FILE), "1.40.0\n").unwrap();
+
+        assert_eq!(find_pinned_spec_from(&child), Some("1.40.0".to_string()));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn version_file_takes_precedence_over_toml() {
+        let root = unique_tmp_dir();
+        fs::write(root.join(VERSION_FILE), "1.40.0\n").unwrap();
+        fs::write(
+            root.join(TOML_FILE),
+            "[toolchain]\nversion = \"1.39.0\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(find_pinned_spec_from(&root), Some("1.40.0".to_string()));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn falls_back_to_toml_when_no_version_file() {
+        let root = unique_tmp_dir();
+        fs::write(
+            root.join(TOML_FILE),
+            "[toolchain]\nversion = \"1.39.0\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(find_pinned_spec_from(&root), Some("1.39.0".to_string()));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn returns_none_without_any_pin_file() {
+        let root = unique_tmp_dir();
+
+        assert_eq!(find_pinned_spec_from(&root), None);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
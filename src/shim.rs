@@ -0,0 +1,31 @@
+use anyhow::Result;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 在 `bin_dir` 下生成一个固定的 shim 脚本，调用时才解析应使用的版本
+///
+/// 这样用户只需要把 `bin_dir` 加入 PATH 一次，之后切换版本（无论是全局默认、
+/// `--use` 覆盖还是项目级别的 pin）都不需要重新编辑 shell 配置文件。
+pub fn write_shim(bin_dir: &Path) -> Result<PathBuf> {
+    fs::create_dir_all(bin_dir)?;
+
+    #[cfg(unix)]
+    {
+        let path = bin_dir.join("sui");
+        fs::write(&path, "#!/usr/bin/env bash\nexec suivm run -- \"$@\"\n")?;
+
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = fs::metadata(&path)?.permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&path, perms)?;
+
+        Ok(path)
+    }
+
+    #[cfg(windows)]
+    {
+        let path = bin_dir.join("sui.cmd");
+        fs::write(&path, "@echo off\r\nsuivm run -- %*\r\n")?;
+        Ok(path)
+    }
+}
@@ -9,3 +9,22 @@ pub fn get_cpu_arch() -> String {
         _ => "unknown".to_string(),
     }
 }
+
+/// 将 Rust 的 `std::env::consts::OS` 映射为 Mysten 发布产物命名中使用的系统名
+pub fn get_asset_os_name() -> String {
+    match get_os_name().as_str() {
+        "macos" => "macos".to_string(),
+        "linux" => "ubuntu".to_string(),
+        "windows" => "windows".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// 当前平台下 `sui` 可执行文件的名称
+pub fn get_binary_name() -> &'static str {
+    if get_os_name() == "windows" {
+        "sui.exe"
+    } else {
+        "sui"
+    }
+}
@@ -1,31 +1,50 @@
+use crate::cache::Cache;
+use crate::project_pin;
+use crate::version_spec::{self, VersionSpec};
 use anyhow::{anyhow, Result};
 use futures_util::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
-use reqwest;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs;
 use std::path::{Path, PathBuf};
 use tokio::io::AsyncWriteExt;
 
-#[derive(Deserialize)]
+/// `download_version` 以及不带 `--limit`/`--all` 的 `list` 默认翻页到这么多页就停止，
+/// 避免为了找一个版本而拉取完整的发布历史（`use`/`uninstall` 只在已安装版本中解析，不翻页）
+pub const DEFAULT_PAGE_LIMIT: u32 = 10;
+const PER_PAGE: u32 = 100;
+
+#[derive(Deserialize, Serialize)]
 struct Release {
     tag_name: String,
     assets: Vec<Asset>,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize, Clone)]
 struct Asset {
     name: String,
     browser_download_url: String,
 }
 
+/// releases 缓存的存储格式：除了 release 列表本身，还记录这份数据覆盖了多少页
+/// （`None` 表示翻完了完整历史），以便判断缓存能否满足某次请求的 `max_pages`
+#[derive(Deserialize, Serialize)]
+struct CachedReleases {
+    max_pages: Option<u32>,
+    releases: Vec<Release>,
+}
+
 pub struct SuiVersionManager {
     base_url: String,
     base_dir: PathBuf,
+    /// 来自 `--use` 全局参数的版本覆盖，优先级高于项目级别的 pin 文件和全局 symlink
+    use_override: Option<String>,
+    cache: Cache,
 }
 
 impl SuiVersionManager {
-    pub fn new() -> Result<Self> {
+    pub fn with_override(use_override: Option<String>) -> Result<Self> {
         // 在用户目录下创建 .suivm 目录
         let home_dir = dirs::home_dir().ok_or_else(|| anyhow!("Could not find home directory"))?;
         let base_dir = home_dir.join(".suivm");
@@ -33,22 +52,121 @@ impl SuiVersionManager {
         // 创建必要的目录结构
         fs::create_dir_all(&base_dir)?;
         fs::create_dir_all(base_dir.join("versions"))?;
+        let cache = Cache::new(&base_dir)?;
 
         Ok(Self {
             base_url: "https://api.github.com/repos/MystenLabs/sui/releases".to_string(),
             base_dir,
+            use_override,
+            cache,
         })
     }
 
-    pub async fn list_remote_versions(&self) -> Result<Vec<(String, bool, bool)>> {
+    /// 获取 release 列表，跟随 `Link: rel="next"` 翻页，直到翻完或达到 `max_pages`
+    ///
+    /// 每次实时翻页的结果都会写入缓存，并记录其覆盖的页数（翻完完整历史则记为
+    /// `None`），这样 `install`、默认的 `list` 等只翻 `DEFAULT_PAGE_LIMIT` 页的请求
+    /// 也能命中缓存，不必每次都重新发起分页请求。读取缓存时，只有当缓存覆盖的
+    /// 页数不少于本次请求的 `max_pages`（或缓存本身就是完整历史）才会被使用，
+    /// 并按 `max_pages` 截断,避免返回比调用方要求的更多数据。
+    async fn fetch_releases(&self, max_pages: Option<u32>) -> Result<Vec<Release>> {
+        if let Some(cached) = self.cache.read_releases() {
+            if let Ok(cached) = serde_json::from_str::<CachedReleases>(&cached) {
+                if Self::cache_covers(cached.max_pages, max_pages) {
+                    return Ok(Self::limit_releases(cached.releases, max_pages));
+                }
+            }
+        }
+
         let client = reqwest::Client::new();
-        let releases: Vec<Release> = client
-            .get(&self.base_url)
-            .header("User-Agent", "sui-version-manager")
-            .send()
-            .await?
-            .json()
-            .await?;
+        let mut releases = Vec::new();
+        let mut url = Some(format!("{}?per_page={}", self.base_url, PER_PAGE));
+        let mut page = 0u32;
+        let mut truncated_by_limit = false;
+
+        while let Some(current_url) = url {
+            page += 1;
+
+            let mut request = client.get(&current_url).header("User-Agent", "sui-version-manager");
+            if let Some(token) = Self::github_token() {
+                request = request.header("Authorization", format!("Bearer {}", token));
+            }
+
+            let response = request.send().await?;
+            let next_url = Self::parse_next_link(response.headers());
+            let page_releases: Vec<Release> = response.json().await?;
+
+            if page_releases.is_empty() {
+                break;
+            }
+            releases.extend(page_releases);
+
+            url = match max_pages {
+                Some(limit) if page >= limit => {
+                    truncated_by_limit = next_url.is_some();
+                    None
+                }
+                _ => next_url,
+            };
+        }
+
+        let cached_max_pages = if truncated_by_limit { Some(page) } else { None };
+        let to_cache = CachedReleases {
+            max_pages: cached_max_pages,
+            releases,
+        };
+        if let Ok(body) = serde_json::to_string(&to_cache) {
+            self.cache.write_releases(&body)?;
+        }
+
+        Ok(Self::limit_releases(to_cache.releases, max_pages))
+    }
+
+    /// 判断一份覆盖了 `cached_max_pages` 页的缓存是否足够满足请求的 `requested_max_pages`
+    ///
+    /// 缓存是完整历史（`None`）时总能满足任意请求；否则只有缓存页数不少于请求
+    /// 页数、且请求本身不是要求完整历史（`None`）时才算满足。
+    fn cache_covers(cached_max_pages: Option<u32>, requested_max_pages: Option<u32>) -> bool {
+        match (cached_max_pages, requested_max_pages) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(cached), Some(requested)) => cached >= requested,
+        }
+    }
+
+    /// 按 `max_pages` 截断 release 列表，使缓存命中时的行为与实时翻页保持一致
+    fn limit_releases(mut releases: Vec<Release>, max_pages: Option<u32>) -> Vec<Release> {
+        if let Some(limit) = max_pages {
+            let max_len = (limit as usize).saturating_mul(PER_PAGE as usize);
+            releases.truncate(max_len);
+        }
+        releases
+    }
+
+    /// 可选的 GitHub 访问令牌，用于把未认证请求的 60 次/小时限流提升到认证额度
+    fn github_token() -> Option<String> {
+        std::env::var("SUIVM_TOKEN")
+            .ok()
+            .or_else(|| std::env::var("GITHUB_TOKEN").ok())
+    }
+
+    /// 从响应头的 `Link` 字段中解析出 `rel="next"` 对应的 URL
+    fn parse_next_link(headers: &reqwest::header::HeaderMap) -> Option<String> {
+        let link = headers.get(reqwest::header::LINK)?.to_str().ok()?;
+        link.split(',').find_map(|part| {
+            let mut segments = part.split(';');
+            let url_part = segments.next()?.trim();
+            let is_next = segments.any(|s| s.trim() == "rel=\"next\"");
+            if is_next {
+                Some(url_part.trim_start_matches('<').trim_end_matches('>').to_string())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub async fn list_remote_versions(&self, max_pages: Option<u32>) -> Result<Vec<(String, bool, bool)>> {
+        let releases = self.fetch_releases(max_pages).await?;
 
         // 获取已安装的版本列表
         let installed_versions = self.list_installed_versions()?;
@@ -61,7 +179,7 @@ impl SuiVersionManager {
             .map(|r| {
                 let version = r.tag_name;
                 let is_installed = installed_versions.contains(&version);
-                let is_default = current_version.as_ref().map_or(false, |v| v == &version);
+                let is_default = current_version.as_ref() == Some(&version);
                 (version, is_installed, is_default)
             })
             .collect();
@@ -69,15 +187,13 @@ impl SuiVersionManager {
         Ok(versions)
     }
 
-    pub async fn download_version(&self, version: &str) -> Result<()> {
+    pub async fn download_version(&self, spec: &VersionSpec, skip_verify: bool) -> Result<String> {
         let client = reqwest::Client::new();
-        let releases: Vec<Release> = client
-            .get(&self.base_url)
-            .header("User-Agent", "sui-version-manager")
-            .send()
-            .await?
-            .json()
-            .await?;
+        let releases = self.fetch_releases(Some(DEFAULT_PAGE_LIMIT)).await?;
+
+        // 将 spec 解析为具体的 tag
+        let tags: Vec<String> = releases.iter().map(|r| r.tag_name.clone()).collect();
+        let version = version_spec::resolve(spec, &tags)?.to_string();
 
         // 找到对应版本的 release
         let release = releases
@@ -85,62 +201,182 @@ impl SuiVersionManager {
             .find(|r| r.tag_name == version)
             .ok_or_else(|| anyhow!("Version {} not found", version))?;
 
-        // 根据系统和架构找到对应的资源文件
+        // 根据系统和架构找到对应的资源文件：先尝试系统+架构精确匹配，再退化为仅系统匹配
+        let os_name = crate::utils::get_asset_os_name();
+        let arch_name = crate::utils::get_cpu_arch();
+
         let asset = release
             .assets
-            .into_iter()
-            .find(|a| a.name.contains("macos") && a.name.contains("arm64"))
-            .ok_or_else(|| anyhow!("No compatible binary found for version {}", version))?;
+            .iter()
+            .find(|a| a.name.contains(&os_name) && a.name.contains(&arch_name))
+            .or_else(|| release.assets.iter().find(|a| a.name.contains(&os_name)))
+            .cloned()
+            .ok_or_else(|| {
+                let available: Vec<&str> = release.assets.iter().map(|a| a.name.as_str()).collect();
+                anyhow!(
+                    "No compatible binary found for version {} on {}/{}. Available assets: {}",
+                    version,
+                    os_name,
+                    arch_name,
+                    available.join(", ")
+                )
+            })?;
+
+        // 查找同一 release 中随附的校验和文件（如果有）
+        let expected_digest = if skip_verify {
+            None
+        } else {
+            match Self::find_digest_asset(&release.assets, &asset.name) {
+                Some(digest_asset) => Some(Self::fetch_digest(&client, digest_asset).await?),
+                None => {
+                    println!(
+                        "Warning: no checksum asset found for {}, skipping verification",
+                        asset.name
+                    );
+                    None
+                }
+            }
+        };
 
         // 创建版本目录
-        let version_dir = self.base_dir.join("versions").join(version);
+        let version_dir = self.base_dir.join("versions").join(&version);
         fs::create_dir_all(&version_dir)?;
 
-        // 开始下载
-        println!("Downloading: {}", asset.name);
-        let response = client
-            .get(&asset.browser_download_url)
-            .header("User-Agent", "sui-version-manager")
-            .send()
-            .await?;
+        // 归档文件按资源名 + 摘要缓存，命中时无需重新下载
+        let cached_path = self.cache.archive_path(&asset.name, expected_digest.as_deref());
 
-        // 获取文件大小
-        let total_size = response.content_length().unwrap_or(0);
-
-        // 设置进度条
-        let pb = ProgressBar::new(total_size);
-        pb.set_style(ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")
-            .unwrap()
-            .progress_chars("#>-"));
-
-        // 下载文件并更新进度条
-        let tgz_path = version_dir.join(&asset.name);
-        let mut file = tokio::fs::File::create(&tgz_path).await?;
-        let mut downloaded: u64 = 0;
-        let mut stream = response.bytes_stream();
-
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk?;
-            file.write_all(&chunk).await?;
-            downloaded = downloaded.saturating_add(chunk.len() as u64);
-            pb.set_position(downloaded);
-        }
+        if cached_path.exists() {
+            println!("Using cached archive: {}", cached_path.display());
+            if let Some(expected) = &expected_digest {
+                Self::verify_file_checksum(&cached_path, expected)?;
+            }
+        } else {
+            // 开始下载
+            println!("Downloading: {}", asset.name);
+            let response = client
+                .get(&asset.browser_download_url)
+                .header("User-Agent", "sui-version-manager")
+                .send()
+                .await?;
+
+            // 获取文件大小
+            let total_size = response.content_length().unwrap_or(0);
+
+            // 设置进度条
+            let pb = ProgressBar::new(total_size);
+            pb.set_style(ProgressStyle::default_bar()
+                .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")
+                .unwrap()
+                .progress_chars("#>-"));
+
+            // 先下载到临时文件，只有在完整接收并通过校验后才移动到缓存路径，
+            // 避免连接中途断开时在缓存目录留下一个会被当作有效缓存复用的半截文件
+            let tmp_path = cached_path.with_extension("part");
+            let download_result: Result<String> = async {
+                let mut file = tokio::fs::File::create(&tmp_path).await?;
+                let mut hasher = Sha256::new();
+                let mut downloaded: u64 = 0;
+                let mut stream = response.bytes_stream();
+
+                while let Some(chunk) = stream.next().await {
+                    let chunk = chunk?;
+                    file.write_all(&chunk).await?;
+                    hasher.update(&chunk);
+                    downloaded = downloaded.saturating_add(chunk.len() as u64);
+                    pb.set_position(downloaded);
+                }
 
-        pb.finish_with_message("Download completed");
+                Ok(format!("{:x}", hasher.finalize()))
+            }
+            .await;
 
-        println!("Extracting files...");
-        // 解压文件
-        self.extract_tgz(&tgz_path, &version_dir)?;
+            let actual = match download_result {
+                Ok(actual) => actual,
+                Err(e) => {
+                    let _ = fs::remove_file(&tmp_path);
+                    return Err(e);
+                }
+            };
+
+            pb.finish_with_message("Download completed");
+
+            if let Some(expected) = &expected_digest {
+                if actual != *expected {
+                    let _ = fs::remove_file(&tmp_path);
+                    return Err(anyhow!(
+                        "Checksum mismatch for {}: expected {}, got {}",
+                        asset.name,
+                        expected,
+                        actual
+                    ));
+                }
+                println!("Checksum verified: {}", actual);
+            }
 
-        // 下载完成后删除 tgz 文件
-        fs::remove_file(tgz_path)?;
+            fs::rename(&tmp_path, &cached_path)?;
+        }
+
+        println!("Extracting files...");
+        // 解压文件（归档文件保留在缓存中，供下次重装复用）
+        self.extract_tgz(&cached_path, &version_dir)?;
 
         println!("Installation completed successfully!");
 
         // 在安装完成后添加 shell 配置建议
         self.suggest_shell_config()?;
 
+        Ok(version)
+    }
+
+    /// 在同一 release 的资源列表中寻找目标文件对应的校验和文件（`.sha256` / `.digest` 后缀）
+    fn find_digest_asset<'a>(assets: &'a [Asset], asset_name: &str) -> Option<&'a Asset> {
+        assets.iter().find(|a| {
+            a.name == format!("{}.sha256", asset_name) || a.name == format!("{}.digest", asset_name)
+        })
+    }
+
+    /// 下载校验和文件并提取出十六进制摘要（兼容 `sha256sum` 的 "<hash>  <filename>" 格式）
+    async fn fetch_digest(client: &reqwest::Client, digest_asset: &Asset) -> Result<String> {
+        let text = client
+            .get(&digest_asset.browser_download_url)
+            .header("User-Agent", "sui-version-manager")
+            .send()
+            .await?
+            .text()
+            .await?;
+
+        text.split_whitespace()
+            .next()
+            .map(|s| s.to_lowercase())
+            .ok_or_else(|| anyhow!("Empty checksum file: {}", digest_asset.name))
+    }
+
+    /// 校验磁盘上已有文件的 SHA-256，用于确认缓存命中的归档没有损坏
+    fn verify_file_checksum(path: &Path, expected: &str) -> Result<()> {
+        use std::io::Read;
+
+        let mut file = fs::File::open(path)?;
+        let mut hasher = Sha256::new();
+        let mut buf = [0u8; 8192];
+
+        loop {
+            let n = file.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buf[..n]);
+        }
+
+        let actual = format!("{:x}", hasher.finalize());
+        if actual != expected {
+            return Err(anyhow!(
+                "Cached archive {} failed checksum verification: expected {}, got {}",
+                path.display(),
+                expected,
+                actual
+            ));
+        }
+
         Ok(())
     }
 
@@ -157,8 +393,11 @@ impl SuiVersionManager {
         Ok(())
     }
 
-    pub fn uninstall_version(&self, version: &str) -> Result<()> {
-        let version_dir = self.base_dir.join("versions").join(version);
+    pub fn uninstall_version(&self, spec: &VersionSpec) -> Result<String> {
+        let installed = self.list_installed_versions()?;
+        let version = version_spec::resolve(spec, &installed)?.to_string();
+
+        let version_dir = self.base_dir.join("versions").join(&version);
 
         // 检查版本是否存在
         if !version_dir.exists() {
@@ -175,11 +414,21 @@ impl SuiVersionManager {
         // 删除版本目录
         fs::remove_dir_all(version_dir)?;
 
-        Ok(())
+        Ok(version)
     }
 
     // 辅助方法：获取当前使用的版本
+    //
+    // 解析顺序：`--use` 覆盖 > 项目级别的 pin 文件（`.suivm-version` / `suivm.toml`）> 全局 symlink
     pub fn get_current_version(&self) -> Result<String> {
+        if let Some(spec_str) = &self.use_override {
+            return self.resolve_installed_spec_str(spec_str);
+        }
+
+        if let Some(pinned) = project_pin::find_pinned_spec() {
+            return self.resolve_installed_spec_str(&pinned);
+        }
+
         let current_link = self.base_dir.join("current");
         if !current_link.exists() {
             return Err(anyhow!("No version currently in use"));
@@ -194,6 +443,18 @@ impl SuiVersionManager {
         Ok(version.to_string())
     }
 
+    /// 将一个原始的 spec 字符串（来自 `--use` 或 pin 文件）解析为具体的已安装版本
+    fn resolve_installed_spec_str(&self, spec_str: &str) -> Result<String> {
+        let spec: VersionSpec = spec_str.parse()?;
+        let installed = self.list_installed_versions()?;
+        version_spec::resolve(&spec, &installed).map(|s| s.to_string())
+    }
+
+    /// 在当前目录写入 `.suivm-version`，将该目录固定为使用给定的 spec
+    pub fn pin_version(&self, spec: &VersionSpec) -> Result<PathBuf> {
+        project_pin::write_pin(&spec.to_string())
+    }
+
     // 新增：获取已安装的版本列表
     pub fn list_installed_versions(&self) -> Result<Vec<String>> {
         let versions_dir = self.base_dir.join("versions");
@@ -250,8 +511,11 @@ impl SuiVersionManager {
         Ok(())
     }
 
-    pub fn set_default_version(&self, version: &str) -> Result<()> {
-        let version_dir = self.base_dir.join("versions").join(version);
+    pub fn set_default_version(&self, spec: &VersionSpec) -> Result<String> {
+        let installed = self.list_installed_versions()?;
+        let version = version_spec::resolve(spec, &installed)?.to_string();
+
+        let version_dir = self.base_dir.join("versions").join(&version);
 
         // 检查版本是否已安装
         if !version_dir.exists() {
@@ -275,11 +539,175 @@ impl SuiVersionManager {
         std::os::windows::fs::symlink_dir(&version_dir, current_link)?;
 
         // 验证二进制文件是否存在
-        let sui_binary = version_dir.join("sui");
+        let sui_binary = version_dir.join(crate::utils::get_binary_name());
         if !sui_binary.exists() {
             return Err(anyhow!("Sui binary not found at: {}", sui_binary.display()));
         }
 
-        Ok(())
+        Ok(version)
+    }
+
+    /// 已安装版本对应的 `sui` 可执行文件路径
+    fn binary_path_for(&self, version: &str) -> Result<PathBuf> {
+        let binary = self
+            .base_dir
+            .join("versions")
+            .join(version)
+            .join(crate::utils::get_binary_name());
+
+        if !binary.exists() {
+            return Err(anyhow!(
+                "Sui binary for version {} not found at: {}",
+                version,
+                binary.display()
+            ));
+        }
+
+        Ok(binary)
+    }
+
+    /// 解析给定 spec 对应的已安装版本，并以继承的 stdio 运行它，转发参数和退出码
+    pub fn exec(&self, spec: &VersionSpec, args: &[String]) -> Result<i32> {
+        let installed = self.list_installed_versions()?;
+        let version = version_spec::resolve(spec, &installed)?.to_string();
+        let binary = self.binary_path_for(&version)?;
+        Self::spawn_and_wait(&binary, args)
+    }
+
+    /// 解析当前应使用的版本（`--use` > 项目 pin > 全局 symlink）并运行它
+    pub fn run(&self, args: &[String]) -> Result<i32> {
+        let version = self.get_current_version()?;
+        let binary = self.binary_path_for(&version)?;
+        Self::spawn_and_wait(&binary, args)
+    }
+
+    fn spawn_and_wait(binary: &Path, args: &[String]) -> Result<i32> {
+        let status = std::process::Command::new(binary).args(args).status()?;
+        Ok(status.code().unwrap_or(1))
+    }
+
+    /// 在 `~/.suivm/bin` 下生成 shim，加入 PATH 后无需在切换版本时重新编辑 rc 文件
+    pub fn remap(&self) -> Result<PathBuf> {
+        let bin_dir = self.base_dir.join("bin");
+        crate::shim::write_shim(&bin_dir)
+    }
+
+    /// 清空下载缓存和 releases 缓存
+    pub fn clear_cache(&self) -> Result<()> {
+        self.cache.clear()
+    }
+
+    /// 收集用于诊断的环境信息，供 `suivm info` 使用
+    pub fn info(&self) -> Result<InfoReport> {
+        let current_version = self.get_current_version().ok();
+        let binary_ok = current_version
+            .as_ref()
+            .map(|v| self.binary_path_for(v).is_ok())
+            .unwrap_or(false);
+
+        Ok(InfoReport {
+            os_name: crate::utils::get_asset_os_name(),
+            cpu_arch: crate::utils::get_cpu_arch(),
+            base_dir: self.base_dir.clone(),
+            current_version,
+            installed_versions: self.list_installed_versions()?,
+            cache_dir: self.cache.dir().to_path_buf(),
+            cache_size_bytes: self.cache.size_bytes()?,
+            binary_present: binary_ok,
+        })
+    }
+}
+
+/// `suivm info` 展示的诊断信息
+pub struct InfoReport {
+    pub os_name: String,
+    pub cpu_arch: String,
+    pub base_dir: PathBuf,
+    pub current_version: Option<String>,
+    pub installed_versions: Vec<String>,
+    pub cache_dir: PathBuf,
+    pub cache_size_bytes: u64,
+    pub binary_present: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue, LINK};
+
+    fn headers_with_link(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(LINK, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn parse_next_link_extracts_next_url() {
+        let headers = headers_with_link(
+            "<https://api.github.com/repos/x/y/releases?page=2>; rel=\"next\", \
+             <https://api.github.com/repos/x/y/releases?page=5>; rel=\"last\"",
+        );
+        assert_eq!(
+            SuiVersionManager::parse_next_link(&headers).as_deref(),
+            Some("https://api.github.com/repos/x/y/releases?page=2")
+        );
+    }
+
+    #[test]
+    fn parse_next_link_returns_none_without_next_rel() {
+        let headers = headers_with_link(
+            "<https://api.github.com/repos/x/y/releases?page=1>; rel=\"first\"",
+        );
+        assert_eq!(SuiVersionManager::parse_next_link(&headers), None);
+    }
+
+    #[test]
+    fn parse_next_link_returns_none_without_link_header() {
+        let headers = HeaderMap::new();
+        assert_eq!(SuiVersionManager::parse_next_link(&headers), None);
+    }
+
+    #[test]
+    fn limit_releases_truncates_to_requested_pages() {
+        let releases: Vec<Release> = (0..(PER_PAGE * 2 + 5))
+            .map(|i| Release {
+                tag_name: format!("v1.{}.0", i),
+                assets: Vec::new(),
+            })
+            .collect();
+
+        let limited = SuiVersionManager::limit_releases(releases, Some(2));
+        assert_eq!(limited.len(), (PER_PAGE * 2) as usize);
+    }
+
+    #[test]
+    fn limit_releases_keeps_everything_without_a_limit() {
+        let releases: Vec<Release> = (0..3)
+            .map(|i| Release {
+                tag_name: format!("v1.{}.0", i),
+                assets: Vec::new(),
+            })
+            .collect();
+
+        let limited = SuiVersionManager::limit_releases(releases, None);
+        assert_eq!(limited.len(), 3);
+    }
+
+    #[test]
+    fn cache_covers_full_history_satisfies_any_request() {
+        assert!(SuiVersionManager::cache_covers(None, Some(1)));
+        assert!(SuiVersionManager::cache_covers(None, None));
+    }
+
+    #[test]
+    fn cache_covers_partial_cache_cannot_satisfy_a_full_history_request() {
+        assert!(!SuiVersionManager::cache_covers(Some(10), None));
+    }
+
+    #[test]
+    fn cache_covers_partial_cache_satisfies_requests_within_its_page_count() {
+        assert!(SuiVersionManager::cache_covers(Some(10), Some(5)));
+        assert!(SuiVersionManager::cache_covers(Some(10), Some(10)));
+        assert!(!SuiVersionManager::cache_covers(Some(5), Some(10)));
     }
 }
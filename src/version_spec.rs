@@ -0,0 +1,163 @@
+use anyhow::{anyhow, Result};
+use semver::{Version, VersionReq};
+use std::fmt;
+use std::str::FromStr;
+
+/// 用户在命令行中指定的版本选择器
+#[derive(Clone, Debug)]
+pub enum VersionSpec {
+    /// `latest`：最新的版本，优先选择非预发布版本
+    Latest,
+    /// `stable`：最新的非预发布版本
+    Stable,
+    /// 精确的 tag，例如 `testnet-v1.39.1`
+    Exact(String),
+    /// semver 范围，例如 `^1.40`、`~1.39.2`、`>=1.38, <1.41`
+    Req(VersionReq),
+}
+
+impl FromStr for VersionSpec {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "latest" => Ok(VersionSpec::Latest),
+            "stable" => Ok(VersionSpec::Stable),
+            _ => match VersionReq::parse(s) {
+                Ok(req) => Ok(VersionSpec::Req(req)),
+                Err(_) => Ok(VersionSpec::Exact(s.to_string())),
+            },
+        }
+    }
+}
+
+impl fmt::Display for VersionSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionSpec::Latest => write!(f, "latest"),
+            VersionSpec::Stable => write!(f, "stable"),
+            VersionSpec::Exact(tag) => write!(f, "{}", tag),
+            VersionSpec::Req(req) => write!(f, "{}", req),
+        }
+    }
+}
+
+/// 去掉 tag 名中 Mysten 使用的前缀（`testnet-v`、`mainnet-v`、`v`），返回剩余的 semver 部分
+pub fn strip_tag_prefix(tag: &str) -> &str {
+    for prefix in ["testnet-v", "mainnet-v", "v"] {
+        if let Some(rest) = tag.strip_prefix(prefix) {
+            return rest;
+        }
+    }
+    tag
+}
+
+/// 将 tag 解析为 semver 版本，解析失败的 tag（非 semver 格式）会被忽略
+fn parse_tag(tag: &str) -> Option<Version> {
+    Version::parse(strip_tag_prefix(tag)).ok()
+}
+
+/// 在给定的 tag 集合中，按照 spec 解析出一个具体的 tag
+pub fn resolve<'a>(spec: &VersionSpec, tags: &'a [String]) -> Result<&'a str> {
+    match spec {
+        VersionSpec::Exact(tag) => tags
+            .iter()
+            .find(|t| t.as_str() == tag)
+            .map(|t| t.as_str())
+            .ok_or_else(|| anyhow!("Version {} not found", tag)),
+        VersionSpec::Latest => pick_highest(tags, None, false)
+            .ok_or_else(|| anyhow!("No versions available to resolve 'latest'")),
+        VersionSpec::Stable => pick_highest(tags, None, true)
+            .ok_or_else(|| anyhow!("No stable (non-prerelease) versions available")),
+        VersionSpec::Req(req) => pick_highest(tags, Some(req), false)
+            .ok_or_else(|| anyhow!("No version matching requirement {} found", req)),
+    }
+}
+
+/// 在候选 tag 中选出满足 requirement（如果有）的最高版本
+///
+/// 优先选择非预发布版本；只有在没有任何非预发布版本满足条件、且 `stable_only`
+/// 为 false 时，才会退而求其次返回预发布版本中的最高值。
+fn pick_highest<'a>(
+    tags: &'a [String],
+    req: Option<&VersionReq>,
+    stable_only: bool,
+) -> Option<&'a str> {
+    let mut candidates: Vec<(&'a str, Version)> = tags
+        .iter()
+        .filter_map(|t| parse_tag(t).map(|v| (t.as_str(), v)))
+        .filter(|(_, v)| req.is_none_or(|r| r.matches(v)))
+        .collect();
+
+    candidates.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let stable = candidates
+        .iter()
+        .rev()
+        .find(|(_, v)| v.pre.is_empty())
+        .map(|(t, _)| *t);
+
+    if stable.is_some() || stable_only {
+        return stable;
+    }
+
+    candidates.last().map(|(t, _)| *t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn strip_tag_prefix_handles_mysten_prefixes() {
+        assert_eq!(strip_tag_prefix("testnet-v1.39.1"), "1.39.1");
+        assert_eq!(strip_tag_prefix("mainnet-v1.38.0"), "1.38.0");
+        assert_eq!(strip_tag_prefix("v1.40.0"), "1.40.0");
+        assert_eq!(strip_tag_prefix("1.40.0"), "1.40.0");
+    }
+
+    #[test]
+    fn resolve_latest_prefers_stable_over_prerelease() {
+        let tags = tags(&["testnet-v1.39.0", "testnet-v1.40.0-rc1", "testnet-v1.38.0"]);
+        assert_eq!(resolve(&VersionSpec::Latest, &tags).unwrap(), "testnet-v1.39.0");
+    }
+
+    #[test]
+    fn resolve_latest_falls_back_to_prerelease_when_no_stable() {
+        let tags = tags(&["v1.40.0-rc1", "v1.39.0-rc2"]);
+        assert_eq!(resolve(&VersionSpec::Latest, &tags).unwrap(), "v1.40.0-rc1");
+    }
+
+    #[test]
+    fn resolve_stable_errors_without_any_stable_version() {
+        let tags = tags(&["v1.40.0-rc1"]);
+        assert!(resolve(&VersionSpec::Stable, &tags).is_err());
+    }
+
+    #[test]
+    fn resolve_req_picks_highest_matching_version() {
+        let tags = tags(&["v1.39.0", "v1.40.0", "v1.40.2", "v1.41.0"]);
+        let spec: VersionSpec = "~1.40".parse().unwrap();
+        assert_eq!(resolve(&spec, &tags).unwrap(), "v1.40.2");
+    }
+
+    #[test]
+    fn resolve_req_errors_without_a_match() {
+        let tags = tags(&["v1.38.0", "v1.39.0"]);
+        let spec: VersionSpec = "^1.40".parse().unwrap();
+        assert!(resolve(&spec, &tags).is_err());
+    }
+
+    #[test]
+    fn resolve_exact_looks_up_the_tag_verbatim() {
+        let tags = tags(&["testnet-v1.39.1", "testnet-v1.40.0"]);
+        assert_eq!(
+            resolve(&VersionSpec::Exact("testnet-v1.39.1".to_string()), &tags).unwrap(),
+            "testnet-v1.39.1"
+        );
+    }
+}